@@ -0,0 +1,106 @@
+//! Emits `log` records and `tracing` events when errors are wrapped, if the corresponding features
+//! are enabled; otherwise everything here compiles to nothing
+
+#[cfg(any(feature = "log", feature = "tracing"))]
+use crate::errors::Severity;
+use crate::errors::Wrapped;
+use crate::report::{Report, Storage};
+
+/// Emits an event for a newly wrapped error
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn wrapped<E>(wrapped: &Wrapped<E>) {
+    #[cfg(feature = "log")]
+    log::log!(
+        target: "ewe",
+        log_level(wrapped.layer_severity()),
+        "wrapped {} as \"{}\" at {}",
+        core::any::type_name::<E>(),
+        wrapped.message(),
+        wrapped.location()
+    );
+
+    #[cfg(feature = "tracing")]
+    {
+        let message = wrapped.message();
+        let severity = wrapped.layer_severity();
+        let location = wrapped.location();
+        let source_type = core::any::type_name::<E>();
+
+        macro_rules! event {
+            ($level:expr) => {
+                tracing::event!(
+                    target: "ewe",
+                    $level,
+                    error.message = message,
+                    %severity,
+                    %location,
+                    source_type,
+                    "error wrapped"
+                )
+            };
+        }
+
+        match severity {
+            Severity::Info => event!(tracing::Level::INFO),
+            Severity::Warn => event!(tracing::Level::WARN),
+            Severity::Error | Severity::Fatal => event!(tracing::Level::ERROR)
+        }
+    }
+}
+
+/// Emits an event for a context frame newly added to a report
+#[inline]
+#[allow(unused_variables)]
+pub(crate) fn context<S: Storage>(report: &Report<S>, frame: &'static str) {
+    #[cfg(feature = "log")]
+    log::log!(
+        target: "ewe",
+        log_level(report.severity()),
+        "added context \"{}\" to report \"{}\" [code {}]",
+        frame,
+        report.message(),
+        report.code()
+    );
+
+    #[cfg(feature = "tracing")]
+    {
+        let message = report.message();
+        let code = report.code();
+        let severity = report.severity();
+        let location = report.location();
+        let payload = report.payload().map(tracing::field::display);
+
+        macro_rules! event {
+            ($level:expr) => {
+                tracing::event!(
+                    target: "ewe",
+                    $level,
+                    frame,
+                    report.message = message,
+                    code,
+                    %severity,
+                    %location,
+                    payload,
+                    "context added"
+                )
+            };
+        }
+
+        match severity {
+            Severity::Info => event!(tracing::Level::INFO),
+            Severity::Warn => event!(tracing::Level::WARN),
+            Severity::Error | Severity::Fatal => event!(tracing::Level::ERROR)
+        }
+    }
+}
+
+/// Maps a severity to the `log` level it is recorded at
+#[cfg(feature = "log")]
+fn log_level(severity: Severity) -> log::Level {
+    match severity {
+        Severity::Info => log::Level::Info,
+        Severity::Warn => log::Level::Warn,
+        Severity::Error | Severity::Fatal => log::Level::Error
+    }
+}
@@ -0,0 +1,426 @@
+//! Contains the error types produced by ewe's extension traits
+
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+use core::iter::FusedIterator;
+use core::panic::Location;
+
+/// Wraps an error with a static message describing what was being attempted when it occurred
+///
+/// The wrapped error is preserved and exposed as the [`source`](Error::source) of the wrapper,
+/// along with the source location at which the wrapping occurred. Displays as `message: source`.
+///
+/// A wrapper's [`Severity`] is available via [`Severe`] when the wrapped error also implements it.
+/// It is the severity given to the wrapper, if any; otherwise it is inherited from the wrapped
+/// error, so a severity set on an inner layer remains visible from the outermost one.
+///
+/// The alternate format (`{:#}`) renders a multi-line tree instead: the message and the location
+/// at which it was wrapped, followed by an indented `caused by:` entry for each error in the
+/// [`chain`](Wrapped::chain) of sources, each in its own alternate format. Nested [`Wrapped`]
+/// errors thus each contribute an entry with their location:
+///
+/// ```text
+/// failed to load config
+///     at src/main.rs:12:10
+///   caused by: failed to parse port
+///     at src/config.rs:40:31
+///   caused by: invalid digit found in string
+/// ```
+///
+/// With the `-` flag only this layer is rendered, without its sources: `{:-}` gives the message
+/// and `{:-#}` adds the location. This is how the entries of the tree are rendered.
+///
+/// # Examples
+/// ```
+/// use ewe::prelude::*;
+///
+/// let result = "x".parse::<u8>().wrap_err("failed to parse port");
+/// let error = result.unwrap_err();
+///
+/// assert_eq!(error.message(), "failed to parse port");
+/// assert_eq!(error.to_string(), "failed to parse port: invalid digit found in string");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Wrapped<E> {
+    message: &'static str,
+    location: &'static Location<'static>,
+    severity: Option<Severity>,
+    source: E
+}
+
+impl<E> Wrapped<E> {
+    /// Wraps `source` with `message`, recording the caller's location
+    #[inline]
+    #[track_caller]
+    pub const fn new(message: &'static str, source: E) -> Self {
+        Self {message, location: Location::caller(), severity: None, source}
+    }
+
+    /// Sets the severity of this wrapping layer, overriding any inherited from the wrapped error
+    ///
+    /// Layers wrapped around this one inherit the severity unless given their own.
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::errors::{Missing, Severe, Severity, Wrapped};
+    ///
+    /// let error = Wrapped::new("cache miss", Missing).with_severity(Severity::Info);
+    /// assert_eq!(error.severity(), Severity::Info);
+    ///
+    /// let error = Wrapped::new("loading", error);
+    /// assert_eq!(error.severity(), Severity::Info);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Returns the message this error was wrapped with
+    #[inline]
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the source location at which the error was wrapped
+    #[inline]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the severity given to this wrapping layer, if any, ignoring the wrapped error
+    #[inline]
+    pub const fn own_severity(&self) -> Option<Severity> {
+        self.severity
+    }
+
+    /// Returns the severity of this wrapping layer: the one given to it, or [`Severity::Error`]
+    ///
+    /// Unlike [`Severe::severity`], this is available whatever the wrapped error, but does not
+    /// inherit a severity from it.
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::errors::{Severity, Wrapped};
+    /// use ewe::prelude::*;
+    ///
+    /// let result: Result<(), &str> = Err("disk full");
+    /// let error = result.wrap_err_as(Severity::Fatal, "saving").unwrap_err();
+    /// assert_eq!(error.layer_severity(), Severity::Fatal);
+    ///
+    /// let error = Wrapped::new("exporting", error);
+    /// assert_eq!(error.layer_severity(), Severity::Error);
+    /// ```
+    #[inline]
+    pub const fn layer_severity(&self) -> Severity {
+        match self.severity {
+            Some(severity) => severity,
+            None => Severity::Error
+        }
+    }
+
+    /// Returns a reference to the wrapped error
+    #[inline]
+    pub const fn inner(&self) -> &E {
+        &self.source
+    }
+
+    /// Unwraps the error, discarding the message and location
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.source
+    }
+}
+
+impl<E: Error> Display for Wrapped<E> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match (f.alternate(), f.sign_minus()) {
+            (false, false) => write!(f, "{}: {}", self.message, self.source),
+            (false, true) => f.write_str(self.message),
+            (true, true) => write!(f, "{}\n    at {}", self.message, self.location),
+            (true, false) => {
+                write!(f, "{}\n    at {}", self.message, self.location)?;
+
+                // Walk the sources here rather than recursing into them, so that those of errors
+                // which do not render their own sources are included too
+                let mut next: Option<&dyn Error> = Some(&self.source);
+                while let Some(error) = next {
+                    write!(f, "\n  caused by: {error:-#}")?;
+                    next = error.source();
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Formats as `message: source`, omitting the location to keep the encoding compact
+#[cfg(feature = "defmt")]
+impl<E: defmt::Format> defmt::Format for Wrapped<E> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}: {}", self.message, self.source);
+    }
+}
+
+impl<E: Error + 'static> Wrapped<E> {
+    /// Returns an iterator over this error followed by each of its transitive sources
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// let error = None::<u8>.ok_or_wrap("no id").wrap_err("no user").unwrap_err();
+    /// let messages: Vec<_> = error.chain().map(|error| error.to_string()).collect();
+    ///
+    /// assert_eq!(messages, [
+    ///     "no user: no id: value was missing",
+    ///     "no id: value was missing",
+    ///     "value was missing"
+    /// ]);
+    /// ```
+    #[inline]
+    pub fn chain(&self) -> Chain<'_> {
+        chain(self)
+    }
+
+    /// Returns the first error in this error's [`chain`](Wrapped::chain) of type `T`, if any
+    ///
+    /// This recovers a concrete error through any number of wrapping layers, including this one.
+    /// It relies only on [`core::error::Error`] and so works in `no_std`.
+    ///
+    /// # Examples
+    /// ```
+    /// use core::num::ParseIntError;
+    /// use ewe::prelude::*;
+    ///
+    /// let error = "x".parse::<u8>().wrap_err("bad id").wrap_err("bad request").unwrap_err();
+    ///
+    /// assert!(error.downcast_ref::<ParseIntError>().is_some());
+    /// assert!(error.downcast_ref::<ewe::errors::Missing>().is_none());
+    /// ```
+    #[inline]
+    pub fn downcast_ref<T: Error + 'static>(&self) -> Option<&T> {
+        self.chain().find_map(|error| error.downcast_ref())
+    }
+}
+
+impl<E: Error + 'static> Error for Wrapped<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl<E: Severe> Severe for Wrapped<E> {
+    #[inline]
+    fn severity(&self) -> Severity {
+        match self.severity {
+            Some(severity) => severity,
+            None => self.source.severity()
+        }
+    }
+}
+
+/// Describes how serious an error is, so that recoverable errors can be routed differently from
+/// fatal ones
+///
+/// Severities are ordered from least to most serious.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Informational; nothing went wrong that requires attention
+    Info,
+
+    /// Something went wrong but was recovered from
+    Warn,
+
+    /// The operation failed
+    #[default]
+    Error,
+
+    /// The failure is unrecoverable
+    Fatal
+}
+
+/// An error which carries a [`Severity`]
+///
+/// This is what lets a [`Wrapped`] error inherit the severity of the error it wraps. It is
+/// implemented for ewe's errors, for the error types in `core` (and `std`, with the `std` feature),
+/// and for boxed `dyn Error`s; for other error types the provided method reports
+/// [`Severity::Error`], so implementing it is a one-liner. Where the orphan rule prevents that
+/// (for errors from other crates), [`Wrapped::layer_severity`] reads a wrapper's own severity
+/// without requiring `Severe` of the wrapped error.
+///
+/// ```
+/// use core::fmt::{Display, Formatter};
+/// use ewe::errors::{Severe, Severity};
+/// use ewe::prelude::*;
+///
+/// #[derive(Debug)]
+/// struct Timeout;
+///
+/// impl Display for Timeout {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+///         f.write_str("timed out")
+///     }
+/// }
+///
+/// impl Severe for Timeout {}
+///
+/// let result: Result<(), _> = Err(Timeout);
+/// let error = result.wrap_err_as(Severity::Warn, "polling").wrap_err("syncing").unwrap_err();
+///
+/// assert_eq!(error.severity(), Severity::Warn);
+/// ```
+pub trait Severe {
+    /// Returns the severity of the error
+    #[inline]
+    fn severity(&self) -> Severity {
+        Severity::Error
+    }
+}
+
+macro_rules! impl_severe {
+    (<$generic:ident> $($error:ty),*) => {
+        $(impl<$generic> Severe for $error {})*
+    };
+    ($($error:ty),*) => {
+        $(impl Severe for $error {})*
+    };
+}
+
+impl_severe!(
+    Missing,
+    core::alloc::LayoutError,
+    core::array::TryFromSliceError,
+    core::cell::BorrowError,
+    core::cell::BorrowMutError,
+    core::char::CharTryFromError,
+    core::char::DecodeUtf16Error,
+    core::char::ParseCharError,
+    core::char::TryFromCharError,
+    core::fmt::Error,
+    core::ffi::FromBytesUntilNulError,
+    core::ffi::FromBytesWithNulError,
+    core::net::AddrParseError,
+    core::num::ParseFloatError,
+    core::num::ParseIntError,
+    core::num::TryFromIntError,
+    core::str::ParseBoolError,
+    core::str::Utf8Error,
+    core::time::TryFromFloatSecsError
+);
+
+#[cfg(feature = "std")]
+impl_severe!(
+    Box<dyn Error>,
+    Box<dyn Error + Send>,
+    Box<dyn Error + Send + Sync>,
+    std::collections::TryReserveError,
+    std::env::JoinPathsError,
+    std::env::VarError,
+    std::ffi::FromVecWithNulError,
+    std::ffi::IntoStringError,
+    std::ffi::NulError,
+    std::io::Error,
+    std::path::StripPrefixError,
+    std::string::FromUtf8Error,
+    std::string::FromUtf16Error,
+    std::sync::mpsc::RecvError,
+    std::sync::mpsc::RecvTimeoutError,
+    std::sync::mpsc::TryRecvError,
+    std::thread::AccessError,
+    std::time::SystemTimeError
+);
+
+#[cfg(feature = "std")]
+impl_severe!(
+    <T>
+    std::io::IntoInnerError<T>,
+    std::sync::PoisonError<T>,
+    std::sync::TryLockError<T>,
+    std::sync::mpsc::SendError<T>,
+    std::sync::mpsc::TrySendError<T>
+);
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            Self::Info => "info",
+            Self::Warn => "warn",
+            Self::Error => "error",
+            Self::Fatal => "fatal"
+        })
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Severity {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Info => defmt::write!(f, "info"),
+            Self::Warn => defmt::write!(f, "warn"),
+            Self::Error => defmt::write!(f, "error"),
+            Self::Fatal => defmt::write!(f, "fatal")
+        }
+    }
+}
+
+/// Indicates that an expected value was absent
+///
+/// This is the source error produced when wrapping an empty [`Option`] via
+/// [`OptionExt`](crate::extensions::OptionExt).
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Missing;
+
+impl Display for Missing {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("value was missing")
+    }
+}
+
+impl Error for Missing {}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Missing {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "value was missing");
+    }
+}
+
+/// Returns an iterator over `error` followed by each of its transitive sources
+///
+/// This is available wherever [`core::error::Error`] is, including `no_std`.
+///
+/// # Examples
+/// ```
+/// use ewe::prelude::*;
+///
+/// let error = "x".parse::<u8>().wrap_err("bad level").unwrap_err();
+///
+/// assert_eq!(ewe::chain(&error).count(), 2);
+/// assert_eq!(ewe::chain(&error).last().unwrap().to_string(), "invalid digit found in string");
+/// ```
+#[inline]
+pub fn chain<'a>(error: &'a (dyn Error + 'static)) -> Chain<'a> {
+    Chain {next: Some(error)}
+}
+
+/// Iterates over an error and its transitive sources; created by [`chain`]
+#[derive(Clone, Debug)]
+pub struct Chain<'a> {
+    next: Option<&'a (dyn Error + 'static)>
+}
+
+impl<'a> Iterator for Chain<'a> {
+    type Item = &'a (dyn Error + 'static);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.next?;
+        self.next = current.source();
+        Some(current)
+    }
+}
+
+impl FusedIterator for Chain<'_> {}
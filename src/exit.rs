@@ -0,0 +1,85 @@
+//! Provides [`ExitReport`] for returning ewe's errors straight from `main`
+
+use std::fmt::Display;
+use std::process::{ExitCode, Termination};
+
+/// The outcome of a program, suitable for returning from `main`
+///
+/// On success the process exits with [`ExitCode::SUCCESS`]. On failure the error is printed to
+/// stderr using its alternate format (which for [`Wrapped`](crate::errors::Wrapped) and
+/// [`Report`](crate::report::Report) renders the full chain with locations), and the process
+/// exits with the code produced by the configured mapping; by default this is `1` for every
+/// error.
+///
+/// # Examples
+/// ```
+/// use ewe::exit::ExitReport;
+/// use ewe::report::Report;
+///
+/// fn run() -> Result<(), Report> {
+///     Ok(())
+/// }
+///
+/// fn main() -> ExitReport<Report> {
+///     ExitReport::from(run()).with_exit_code(|report| match report.code() {
+///         0 => 1,
+///         code => u8::try_from(code).unwrap_or(1)
+///     })
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ExitReport<E> {
+    result: Result<(), E>,
+    exit_code: fn(&E) -> u8
+}
+
+impl<E> ExitReport<E> {
+    /// Creates an exit report from the outcome of a program
+    #[inline]
+    pub fn new(result: Result<(), E>) -> Self {
+        Self {result, exit_code: |_| 1}
+    }
+
+    /// Sets the mapping from errors to process exit codes
+    ///
+    /// Mapping an error to `0` reports success to the operating system, which is rarely intended.
+    #[inline]
+    #[must_use]
+    pub fn with_exit_code(mut self, exit_code: fn(&E) -> u8) -> Self {
+        self.exit_code = exit_code;
+        self
+    }
+
+    /// Returns the exit code the report will terminate the process with
+    #[inline]
+    pub fn exit_code(&self) -> u8 {
+        match &self.result {
+            Ok(()) => 0,
+            Err(error) => (self.exit_code)(error)
+        }
+    }
+
+    /// Returns the outcome of the program
+    #[inline]
+    pub fn into_result(self) -> Result<(), E> {
+        self.result
+    }
+}
+
+impl<E> From<Result<(), E>> for ExitReport<E> {
+    #[inline]
+    fn from(result: Result<(), E>) -> Self {
+        Self::new(result)
+    }
+}
+
+impl<E: Display> Termination for ExitReport<E> {
+    fn report(self) -> ExitCode {
+        let exit_code = self.exit_code();
+        if let Err(error) = self.result {
+            eprintln!("Error: {error:#}");
+        }
+
+        ExitCode::from(exit_code)
+    }
+}
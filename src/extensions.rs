@@ -0,0 +1,244 @@
+//! Provides extension traits which wrap errors as they propagate
+
+use crate::emit;
+use crate::errors::{Missing, Severity, Wrapped};
+use crate::report::{Report, Storage};
+
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+use crate::interop::Link;
+
+/// Extends [`Result`] with methods for wrapping its error
+pub trait ResultExt<T, E> {
+    /// Wraps the error, if any, with a static message describing what was being attempted
+    ///
+    /// The location of the call is recorded in the resulting [`Wrapped`], which inherits the
+    /// [`Severity`] of the error if it carries one (see [`Severe`](crate::errors::Severe)).
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// fn port(text: &str) -> Result<u16, ewe::errors::Wrapped<core::num::ParseIntError>> {
+    ///     text.parse().wrap_err("invalid port")
+    /// }
+    ///
+    /// assert_eq!(port("8080").unwrap(), 8080);
+    /// assert_eq!(
+    ///     port("http").unwrap_err().to_string(),
+    ///     "invalid port: invalid digit found in string"
+    /// );
+    /// ```
+    #[track_caller]
+    fn wrap_err(self, message: &'static str) -> Result<T, Wrapped<E>>;
+
+    /// Wraps the error, if any, like [`wrap_err`](ResultExt::wrap_err), additionally attaching
+    /// `severity` to the wrapping layer
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::errors::Severity;
+    /// use ewe::prelude::*;
+    ///
+    /// let error = "x".parse::<u8>().wrap_err_as(Severity::Warn, "bad hint").unwrap_err();
+    /// assert_eq!(error.severity(), Severity::Warn);
+    /// ```
+    #[track_caller]
+    fn wrap_err_as(self, severity: Severity, message: &'static str) -> Result<T, Wrapped<E>>;
+
+    /// Runs `f` on the error, if any, and returns the result unchanged
+    ///
+    /// Useful for logging an error at the point of occurrence while still propagating it with
+    /// `?`. Since the result's type is unchanged, this composes freely with
+    /// [`wrap_err`](ResultExt::wrap_err) before or after.
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// let mut failures = 0;
+    /// let result = "x".parse::<u8>()
+    ///     .tap_err(|_| failures += 1)
+    ///     .wrap_err("bad count")
+    ///     .tap_err(|error| assert_eq!(error.message(), "bad count"));
+    ///
+    /// assert!(result.is_err());
+    /// assert_eq!(failures, 1);
+    /// ```
+    fn tap_err(self, f: impl FnOnce(&E)) -> Self;
+
+    /// Converts the error, if any, into an [`anyhow::Error`] with one entry per error in its chain
+    ///
+    /// Each entry holds just that error's part of the chain: for a [`Wrapped`] error, its message
+    /// and the location at which it was wrapped, and for a [`Report`], its context frames and
+    /// message with their locations. The entries are rendered copies, so the result cannot be
+    /// downcast to the original error; convert with `?` instead to keep it.
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// let error = None::<u8>.ok_or_wrap("no id").wrap_err("no user").into_anyhow().unwrap_err();
+    /// let messages: Vec<_> = error.chain().map(|error| error.to_string()).collect();
+    ///
+    /// assert!(messages[0].starts_with("no user\n    at "));
+    /// assert!(messages[1].starts_with("no id\n    at "));
+    /// assert_eq!(messages[2], "value was missing");
+    /// ```
+    #[cfg(feature = "anyhow")]
+    fn into_anyhow(self) -> Result<T, anyhow::Error>
+        where E: core::error::Error + Send + Sync + 'static;
+
+    /// Converts the error, if any, into an [`eyre::Report`] with one entry per error in its chain
+    ///
+    /// The entries are as for [`into_anyhow`](ResultExt::into_anyhow).
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// let error = "x".parse::<u8>().wrap_err("bad id").into_eyre().unwrap_err();
+    ///
+    /// assert_eq!(error.chain().count(), 2);
+    /// assert_eq!(error.root_cause().to_string(), "invalid digit found in string");
+    /// ```
+    #[cfg(feature = "eyre")]
+    fn into_eyre(self) -> Result<T, eyre::Report>
+        where E: core::error::Error + Send + Sync + 'static;
+}
+
+impl<T, E> ResultExt<T, E> for Result<T, E> {
+    #[inline]
+    #[track_caller]
+    fn wrap_err(self, message: &'static str) -> Result<T, Wrapped<E>> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(emitted(Wrapped::new(message, error)))
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn wrap_err_as(self, severity: Severity, message: &'static str) -> Result<T, Wrapped<E>> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(error) => Err(emitted(Wrapped::new(message, error).with_severity(severity)))
+        }
+    }
+
+    #[inline]
+    fn tap_err(self, f: impl FnOnce(&E)) -> Self {
+        if let Err(error) = &self {
+            f(error);
+        }
+
+        self
+    }
+
+    #[cfg(feature = "anyhow")]
+    fn into_anyhow(self) -> Result<T, anyhow::Error>
+        where E: core::error::Error + Send + Sync + 'static
+    {
+        self.map_err(|error| anyhow::Error::new(Link::relink(&error)))
+    }
+
+    #[cfg(feature = "eyre")]
+    fn into_eyre(self) -> Result<T, eyre::Report>
+        where E: core::error::Error + Send + Sync + 'static
+    {
+        self.map_err(|error| eyre::Report::new(Link::relink(&error)))
+    }
+}
+
+/// Extends [`Option`] with methods for converting an absent value into a wrapped error
+pub trait OptionExt<T> {
+    /// Converts `None` into a [`Missing`] error wrapped with `message`
+    ///
+    /// The location of the call is recorded in the resulting [`Wrapped`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// assert_eq!(Some(3).ok_or_wrap("no retries configured").unwrap(), 3);
+    ///
+    /// let error = None::<u8>.ok_or_wrap("no retries configured").unwrap_err();
+    /// assert_eq!(error.to_string(), "no retries configured: value was missing");
+    /// ```
+    #[track_caller]
+    fn ok_or_wrap(self, message: &'static str) -> Result<T, Wrapped<Missing>>;
+
+    /// Converts `None` into a [`Missing`] error wrapped with the message returned by `message`
+    ///
+    /// The closure is only invoked if the value is absent. The location of the call is recorded
+    /// in the resulting [`Wrapped`].
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    ///
+    /// let error = None::<u8>.ok_or_wrap_with(|| "no retries configured").unwrap_err();
+    /// assert_eq!(error.message(), "no retries configured");
+    /// ```
+    #[track_caller]
+    fn ok_or_wrap_with(self, message: impl FnOnce() -> &'static str)
+        -> Result<T, Wrapped<Missing>>;
+}
+
+impl<T> OptionExt<T> for Option<T> {
+    #[inline]
+    #[track_caller]
+    fn ok_or_wrap(self, message: &'static str) -> Result<T, Wrapped<Missing>> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(emitted(Wrapped::new(message, Missing)))
+        }
+    }
+
+    #[inline]
+    #[track_caller]
+    fn ok_or_wrap_with(self, message: impl FnOnce() -> &'static str)
+        -> Result<T, Wrapped<Missing>> {
+        match self {
+            Some(value) => Ok(value),
+            None => Err(emitted(Wrapped::new(message(), Missing)))
+        }
+    }
+}
+
+/// Extends [`Result`]s holding a [`Report`] with methods for adding context as they propagate
+pub trait ContextExt<T, S> {
+    /// Adds a context frame to the report, if any; see [`Report::context`]
+    ///
+    /// # Examples
+    /// ```
+    /// use ewe::prelude::*;
+    /// use ewe::report::Report;
+    ///
+    /// fn calibrate() -> Result<(), Report> {
+    ///     Err(Report::new(3, "gain too high"))
+    /// }
+    ///
+    /// let report = calibrate().context("starting up").unwrap_err();
+    /// assert_eq!(report.to_string(), "starting up: gain too high [code 3]");
+    /// ```
+    #[track_caller]
+    fn context(self, frame: &'static str) -> Result<T, Report<S>>;
+}
+
+impl<T, S: Storage> ContextExt<T, S> for Result<T, Report<S>> {
+    #[inline]
+    #[track_caller]
+    fn context(self, frame: &'static str) -> Result<T, Report<S>> {
+        match self {
+            Ok(value) => Ok(value),
+            Err(report) => Err(report.context(frame))
+        }
+    }
+}
+
+/// Emits the configured event for a newly wrapped error, if any, and passes it through
+#[inline]
+fn emitted<E>(wrapped: Wrapped<E>) -> Wrapped<E> {
+    emit::wrapped(&wrapped);
+    wrapped
+}
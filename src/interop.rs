@@ -0,0 +1,42 @@
+//! Converts errors into `anyhow` and `eyre` reports, if the corresponding features are enabled
+
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+use crate::errors::chain;
+
+/// One error of a chain rebuilt for conversion, displaying only its own part of the original
+#[derive(Debug)]
+pub(crate) struct Link {
+    text: String,
+    source: Option<Box<Link>>
+}
+
+impl Link {
+    /// Rebuilds the chain of `error`, rendering each of its errors alone in alternate format
+    ///
+    /// [`Wrapped`](crate::errors::Wrapped) errors display their sources as part of their own
+    /// message, so converting them as they are would repeat each source once per layer in the
+    /// report; rendering them with the `-` flag instead keeps just their message and location.
+    pub(crate) fn relink(error: &(dyn Error + 'static)) -> Self {
+        let mut texts: Vec<_> = chain(error).map(|error| format!("{error:-#}")).collect();
+        let mut link = Self {text: texts.pop().unwrap_or_default(), source: None};
+        while let Some(text) = texts.pop() {
+            link = Self {text, source: Some(Box::new(link))};
+        }
+
+        link
+    }
+}
+
+impl Display for Link {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+impl Error for Link {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source.as_deref().map(|link| link as &(dyn Error + 'static))
+    }
+}
@@ -6,3 +6,61 @@
 //#![allow(clippy::match_bool)]
 
 //! # Ewe: **E**rror-**W**rapping **E**xtensions
+//!
+//! Ewe provides small, `no_std`-friendly utilities for application-layer errors: extension
+//! traits which wrap errors with context as they propagate, while preserving the original error
+//! as the [`source`](core::error::Error::source) of the wrapper. As an alternative to nesting
+//! error types, [`Report`](report::Report) stores an error code, static context frames, and a
+//! numeric payload in a single value; its frames live either inline, without allocating, or on
+//! the heap with `std`.
+//!
+//! # Usage
+//! Glob-import the prelude to bring the extension traits into scope:
+//!
+//! ```
+//! use ewe::prelude::*;
+//!
+//! let error = "256".parse::<u8>().wrap_err("bad channel").unwrap_err();
+//! assert_eq!(error.to_string(), "bad channel: number too large to fit in target type");
+//! ```
+//!
+//! # Formatting
+//! The plain (`{}`) format of ewe's errors contains only their messages, codes, and payloads, and
+//! is produced entirely by `core::fmt`: it does not depend on locale or target, so it is safe to
+//! snapshot in tests. The alternate (`{:#}`) format additionally contains source locations, whose
+//! file paths follow the host's conventions (for example, path separators on Windows).
+//!
+//! # Features
+//! * `std` (default): links the standard library and enables [`exit`]; without it ewe is `no_std`
+//! * `default-heap`: makes [`Heap`](report::Heap) rather than [`Inline<8>`](report::Inline) the
+//!   storage of the default [`Report`](report::Report); implies `std`. Unlike the other features
+//!   this changes a type (see [`DefaultStorage`](report::DefaultStorage)), so it is best left to
+//!   binaries
+//! * `log`: emits a [`log`](https://docs.rs/log) record each time an error is wrapped or a
+//!   context frame is added, at a level matching the severity given to the wrapping layer or
+//!   report (error unless given; inherited severities are not known when wrapping generically)
+//! * `tracing`: emits a [`tracing`](https://docs.rs/tracing) event with structured fields for the
+//!   same occasions: `error.message`, `severity`, `location`, and `source_type` when an error is
+//!   wrapped; `frame`, `report.message`, `code`, `severity`, `location`, and `payload` when a
+//!   context frame is added
+//! * `anyhow`, `eyre`: add `ResultExt::into_anyhow` and `ResultExt::into_eyre` respectively,
+//!   converting errors into [`anyhow`](https://docs.rs/anyhow) or [`eyre`](https://docs.rs/eyre)
+//!   reports which keep each wrapping layer (with its location) as a separate entry; both imply
+//!   `std`
+//! * `defmt`: implements [`defmt::Format`](https://docs.rs/defmt) for ewe's errors, severities,
+//!   and payloads, for compact logging on targets which cannot afford `core::fmt`; locations are
+//!   omitted
+
+pub mod errors;
+#[cfg(feature = "std")]
+pub mod exit;
+pub mod extensions;
+pub mod prelude;
+pub mod report;
+
+mod emit;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+mod interop;
+mod macros;
+
+pub use errors::chain;
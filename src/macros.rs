@@ -0,0 +1,67 @@
+//! Provides macros for early-returning errors
+
+/// Returns early with an error
+///
+/// With two arguments, creates a [`Report`](crate::report::Report) from an error code and a
+/// message, recording the location of the macro invocation. With a single argument, returns the
+/// given error as-is. In both cases the error is converted into the function's error type via
+/// [`From`], so neither `alloc` nor `std` is required.
+///
+/// # Examples
+/// ```
+/// use ewe::bail;
+/// use ewe::report::Report;
+///
+/// fn check(level: u8) -> Result<u8, Report> {
+///     if level > 10 {
+///         bail!(4, "level too high");
+///     }
+///
+///     Ok(level)
+/// }
+///
+/// assert_eq!(check(3).unwrap(), 3);
+/// assert_eq!(check(11).unwrap_err().to_string(), "level too high [code 4]");
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($code:expr, $message:expr $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::From::from(
+            $crate::report::Report::new($code, $message)
+        ))
+    };
+    ($error:expr $(,)?) => {
+        return ::core::result::Result::Err(::core::convert::From::from($error))
+    };
+}
+
+/// Returns early with an error if a condition does not hold
+///
+/// Accepts the same error arguments as [`bail!`] following the condition.
+///
+/// # Examples
+/// ```
+/// use ewe::ensure;
+/// use ewe::report::Report;
+///
+/// fn check(level: u8) -> Result<u8, Report> {
+///     ensure!(level <= 10, 4, "level too high");
+///     Ok(level)
+/// }
+///
+/// assert_eq!(check(3).unwrap(), 3);
+/// assert_eq!(check(11).unwrap_err().code(), 4);
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($condition:expr, $code:expr, $message:expr $(,)?) => {
+        if !$condition {
+            $crate::bail!($code, $message);
+        }
+    };
+    ($condition:expr, $error:expr $(,)?) => {
+        if !$condition {
+            $crate::bail!($error);
+        }
+    };
+}
@@ -0,0 +1,24 @@
+//! Brings ewe's extension traits into scope; intended to be glob-imported
+//!
+//! The full prelude includes [`ContextExt`], whose [`context`](ContextExt::context) method shares
+//! its name with methods from other error-handling crates (and possibly your own). Where that
+//! would be ambiguous, glob-import [`minimal`] instead, which brings only the traits for wrapping
+//! errors (and querying their severity) into scope.
+
+pub use crate::extensions::ContextExt;
+pub use minimal::*;
+
+/// Brings only ewe's error-wrapping extension traits and [`Severe`] into scope; intended to be
+/// glob-imported
+///
+/// # Examples
+/// ```
+/// use ewe::prelude::minimal::*;
+///
+/// let error = None::<u8>.ok_or_wrap("no channel").unwrap_err();
+/// assert_eq!(error.to_string(), "no channel: value was missing");
+/// ```
+pub mod minimal {
+    pub use crate::errors::Severe;
+    pub use crate::extensions::{OptionExt, ResultExt};
+}
@@ -0,0 +1,461 @@
+//! Provides [`Report`], an error report holding static context frames in a configurable
+//! [`Storage`]
+
+use core::error::Error;
+use core::fmt::{Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::panic::Location;
+
+use crate::emit;
+use crate::errors::{Severe, Severity};
+
+/// The [`Storage`] used by [`Report`] when none is specified: [`Inline<8>`](Inline), or [`Heap`]
+/// with the `default-heap` feature
+///
+/// Since enabling `default-heap` anywhere in a build changes this type (and with it whether the
+/// default `Report` is [`Copy`] and how many frames it retains), libraries should name their
+/// storage explicitly rather than relying on the default.
+#[cfg(not(feature = "default-heap"))]
+pub type DefaultStorage = Inline<8>;
+
+/// The [`Storage`] used by [`Report`] when none is specified: [`Inline<8>`](Inline), or [`Heap`]
+/// with the `default-heap` feature
+///
+/// Since enabling `default-heap` anywhere in a build changes this type (and with it whether the
+/// default `Report` is [`Copy`] and how many frames it retains), libraries should name their
+/// storage explicitly rather than relying on the default.
+#[cfg(feature = "default-heap")]
+pub type DefaultStorage = Heap;
+
+/// An error report holding an error code, a message, context frames, and an optional numeric
+/// [`Payload`]
+///
+/// Context frames are static strings describing what was being attempted, added as the report
+/// propagates outwards via [`context`](Report::context). They are held in the [`Storage`] `S`:
+/// either on the heap without limit via [`Heap`], or inline without allocation via [`Inline`],
+/// which retains only the innermost frames once full (since they are closest to the original
+/// failure) and counts the rest as [`elided`](Report::elided).
+///
+/// The source location at which the report was created is recorded as well; this is typically
+/// the site of a [`bail!`](crate::bail) or [`ensure!`](crate::ensure). Reports also carry a
+/// [`Severity`], defaulting to [`Severity::Error`], which is preserved as context is added.
+///
+/// Displays as the context frames from outermost to innermost, followed by the message, code,
+/// and payload (if any). The alternate format (`{:#}`) instead renders a multi-line tree: the
+/// outermost frame followed by an indented `caused by:` entry for each inner frame and finally the
+/// message, each with the location at which it was added on the following line.
+///
+/// # Examples
+/// ```
+/// use ewe::report::{Inline, Report};
+///
+/// let report = Report::<Inline<4>>::new(7, "sample out of range")
+///     .with_payload(300u16)
+///     .context("decoding frame")
+///     .context("reading sensor");
+///
+/// assert_eq!(
+///     report.to_string(),
+///     "reading sensor: decoding frame: sample out of range [code 7, value 300]"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Report<S = DefaultStorage> {
+    code: u32,
+    message: &'static str,
+    location: &'static Location<'static>,
+    severity: Severity,
+    frames: S,
+    count: usize,
+    payload: Option<Payload>
+}
+
+impl<S: Storage> Report<S> {
+    /// Creates a report with the given error code and message, no context and no payload
+    ///
+    /// The caller's location is recorded as the report's origin.
+    #[inline]
+    #[track_caller]
+    pub fn new(code: u32, message: &'static str) -> Self {
+        Self {
+            code,
+            message,
+            location: Location::caller(),
+            severity: Severity::Error,
+            frames: S::empty(),
+            count: 0,
+            payload: None
+        }
+    }
+
+    /// Attaches a numeric payload to the report, replacing any existing payload
+    #[inline]
+    #[must_use]
+    pub fn with_payload(mut self, payload: impl Into<Payload>) -> Self {
+        self.payload = Some(payload.into());
+        self
+    }
+
+    /// Sets the severity of the report
+    #[inline]
+    #[must_use]
+    pub fn with_severity(mut self, severity: Severity) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Adds a context frame describing what was being attempted when the error occurred
+    ///
+    /// The caller's location is recorded in the frame. If the storage is full, the frame is
+    /// counted (see [`elided`](Report::elided)) but not stored.
+    #[inline]
+    #[must_use]
+    #[track_caller]
+    pub fn context(mut self, frame: &'static str) -> Self {
+        self.frames.push(Frame {message: frame, location: Location::caller()});
+        self.count = self.count.saturating_add(1);
+        emit::context(&self, frame);
+        self
+    }
+
+    /// Returns the error code
+    #[inline]
+    pub const fn code(&self) -> u32 {
+        self.code
+    }
+
+    /// Returns the error message
+    #[inline]
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the source location at which the report was created
+    #[inline]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+
+    /// Returns the severity of the report
+    #[inline]
+    pub const fn severity(&self) -> Severity {
+        self.severity
+    }
+
+    /// Returns the payload, if any
+    #[inline]
+    pub const fn payload(&self) -> Option<Payload> {
+        self.payload
+    }
+
+    /// Returns the stored context frames, innermost first
+    #[inline]
+    pub fn frames(&self) -> &[Frame] {
+        self.frames.frames()
+    }
+
+    /// Returns the number of context frames which were added but not stored for lack of capacity
+    #[inline]
+    pub fn elided(&self) -> usize {
+        self.count - self.frames().len()
+    }
+}
+
+impl<S: Storage> Display for Report<S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let separator = match f.alternate() {
+            true => "\n  caused by: ",
+            false => ": "
+        };
+
+        if self.elided() > 0 {
+            write!(f, "[{} more]{separator}", self.elided())?;
+        }
+
+        for frame in self.frames().iter().rev() {
+            Display::fmt(frame, f)?;
+            f.write_str(separator)?;
+        }
+
+        write!(f, "{} [code {}", self.message, self.code)?;
+        if let Some(payload) = self.payload {
+            write!(f, ", value {payload}")?;
+        }
+
+        f.write_str("]")?;
+        match f.alternate() {
+            true => write!(f, "\n    at {}", self.location),
+            false => Ok(())
+        }
+    }
+}
+
+/// Formats like [`Display`], omitting locations to keep the encoding compact
+#[cfg(feature = "defmt")]
+impl<S: Storage> defmt::Format for Report<S> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        if self.elided() > 0 {
+            defmt::write!(f, "[{=usize} more]: ", self.elided());
+        }
+
+        for frame in self.frames().iter().rev() {
+            defmt::write!(f, "{=str}: ", frame.message);
+        }
+
+        defmt::write!(f, "{=str} [code {=u32}", self.message, self.code);
+        if let Some(payload) = self.payload {
+            defmt::write!(f, ", value {}", payload);
+        }
+
+        defmt::write!(f, "]");
+    }
+}
+
+impl<S: Storage + Debug> Error for Report<S> {}
+
+impl<S: Storage> Severe for Report<S> {
+    #[inline]
+    fn severity(&self) -> Severity {
+        self.severity
+    }
+}
+
+/// A context frame within a [`Report`]
+///
+/// Displays as its message; the alternate format (`{:#}`) adds the location on a second line.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Frame {
+    message: &'static str,
+    location: &'static Location<'static>
+}
+
+impl Frame {
+    /// Returns the message describing what was being attempted
+    #[inline]
+    pub const fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the source location at which the frame was added
+    #[inline]
+    pub const fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl Display for Frame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match f.alternate() {
+            true => write!(f, "{}\n    at {}", self.message, self.location),
+            false => f.write_str(self.message)
+        }
+    }
+}
+
+/// Formats as its message, omitting the location to keep the encoding compact
+#[cfg(feature = "defmt")]
+impl defmt::Format for Frame {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "{=str}", self.message);
+    }
+}
+
+/// A strategy for holding the context frames of a [`Report`]
+pub trait Storage {
+    /// Returns storage holding no frames
+    fn empty() -> Self;
+
+    /// Stores `frame` after any frames already stored, or discards it if the storage is full
+    fn push(&mut self, frame: Frame);
+
+    /// Returns the stored frames in the order they were pushed
+    fn frames(&self) -> &[Frame];
+}
+
+/// Stores up to `N` context frames inline, without allocating
+///
+/// Once full, further frames are discarded.
+#[derive(Copy, Clone)]
+pub struct Inline<const N: usize> {
+    frames: [Frame; N],
+    len: usize
+}
+
+impl<const N: usize> Storage for Inline<N> {
+    #[inline]
+    fn empty() -> Self {
+        // The placeholder location is never observed since only stored frames are exposed
+        Self {frames: [Frame {message: "", location: Location::caller()}; N], len: 0}
+    }
+
+    #[inline]
+    fn push(&mut self, frame: Frame) {
+        if let Some(slot) = self.frames.get_mut(self.len) {
+            *slot = frame;
+            self.len += 1;
+        }
+    }
+
+    #[inline]
+    fn frames(&self) -> &[Frame] {
+        &self.frames[..self.len]
+    }
+}
+
+impl<const N: usize> Debug for Inline<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_list().entries(self.frames()).finish()
+    }
+}
+
+// Only the stored frames take part in comparisons; unused slots hold placeholders
+
+impl<const N: usize> PartialEq for Inline<N> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.frames() == other.frames()
+    }
+}
+
+impl<const N: usize> Eq for Inline<N> {}
+
+impl<const N: usize> Hash for Inline<N> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.frames().hash(state);
+    }
+}
+
+/// Stores any number of context frames on the heap
+#[cfg(feature = "std")]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Heap {
+    frames: Vec<Frame>
+}
+
+#[cfg(feature = "std")]
+impl Storage for Heap {
+    #[inline]
+    fn empty() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    fn push(&mut self, frame: Frame) {
+        self.frames.push(frame);
+    }
+
+    #[inline]
+    fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+}
+
+/// A numeric value attached to a [`Report`], such as the value which caused the failure
+///
+/// Displays as plain decimal: floating point payloads never switch to scientific notation,
+/// whatever their magnitude, and display the shortest representation which round-trips through
+/// their original width (so `0.1f32` displays as `0.1`).
+///
+/// Floating point payloads are compared and hashed by their bit patterns, so that payloads (and
+/// thus reports) are [`Eq`] and [`Hash`]; consequently a NaN payload equals itself, while `0.0`
+/// and `-0.0` are distinct.
+#[derive(Copy, Clone, Debug)]
+pub enum Payload {
+    /// An unsigned integer
+    Unsigned(u128),
+
+    /// A signed integer
+    Signed(i128),
+
+    /// A single-precision floating point
+    Float32(f32),
+
+    /// A double-precision floating point
+    Float64(f64)
+}
+
+impl PartialEq for Payload {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unsigned(lhs), Self::Unsigned(rhs)) => lhs == rhs,
+            (Self::Signed(lhs), Self::Signed(rhs)) => lhs == rhs,
+            (Self::Float32(lhs), Self::Float32(rhs)) => lhs.to_bits() == rhs.to_bits(),
+            (Self::Float64(lhs), Self::Float64(rhs)) => lhs.to_bits() == rhs.to_bits(),
+            _ => false
+        }
+    }
+}
+
+impl Eq for Payload {}
+
+impl Hash for Payload {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        core::mem::discriminant(self).hash(state);
+        match self {
+            Self::Unsigned(value) => value.hash(state),
+            Self::Signed(value) => value.hash(state),
+            Self::Float32(value) => value.to_bits().hash(state),
+            Self::Float64(value) => value.to_bits().hash(state)
+        }
+    }
+}
+
+impl Display for Payload {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unsigned(value) => Display::fmt(value, f),
+            Self::Signed(value) => Display::fmt(value, f),
+            Self::Float32(value) => Display::fmt(value, f),
+            Self::Float64(value) => Display::fmt(value, f)
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Payload {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        match self {
+            Self::Unsigned(value) => defmt::write!(f, "{=u128}", value),
+            Self::Signed(value) => defmt::write!(f, "{=i128}", value),
+            Self::Float32(value) => defmt::write!(f, "{=f32}", value),
+            Self::Float64(value) => defmt::write!(f, "{=f64}", value)
+        }
+    }
+}
+
+macro_rules! impl_payload_from {
+    ($variant:ident: $target:ty => $($source:ty),*) => {
+        $(
+            impl From<$source> for Payload {
+                #[inline]
+                fn from(value: $source) -> Self {
+                    Self::$variant(<$target>::from(value))
+                }
+            }
+        )*
+    };
+}
+
+impl_payload_from!(Unsigned: u128 => u8, u16, u32, u64, u128);
+impl_payload_from!(Signed: i128 => i8, i16, i32, i64, i128);
+impl_payload_from!(Float32: f32 => f32);
+impl_payload_from!(Float64: f64 => f64);
+
+impl From<usize> for Payload {
+    #[inline]
+    fn from(value: usize) -> Self {
+        // usize is at most 128 bits on all supported platforms
+        Self::Unsigned(value as u128)
+    }
+}
+
+impl From<isize> for Payload {
+    #[inline]
+    fn from(value: isize) -> Self {
+        // isize is at most 128 bits on all supported platforms
+        Self::Signed(value as i128)
+    }
+}
@@ -0,0 +1,41 @@
+//! Tests for iterating over error source chains
+
+use ewe::prelude::*;
+use core::error::Error;
+
+#[test]
+fn chain_single() {
+    let error = ewe::errors::Missing;
+    let mut chain = ewe::chain(&error);
+
+    assert_eq!(chain.next().unwrap().to_string(), "value was missing");
+    assert!(chain.next().is_none());
+    assert!(chain.next().is_none());
+}
+
+#[test]
+fn chain_nested() {
+    let error = None::<u8>
+        .ok_or_wrap("first")
+        .wrap_err("second")
+        .wrap_err("third")
+        .unwrap_err();
+
+    let messages: Vec<_> = error.chain().map(|error| error.to_string()).collect();
+    assert_eq!(messages, [
+        "third: second: first: value was missing",
+        "second: first: value was missing",
+        "first: value was missing",
+        "value was missing"
+    ]);
+}
+
+#[test]
+fn chain_matches_sources() {
+    let error = None::<u8>.ok_or_wrap("first").wrap_err("second").unwrap_err();
+    let mut chain = ewe::chain(&error);
+
+    chain.next();
+    let source = error.source().unwrap();
+    assert!(core::ptr::addr_eq(chain.next().unwrap(), source));
+}
@@ -0,0 +1,38 @@
+//! Tests for downcasting through wrapped errors
+
+use ewe::errors::{Missing, Wrapped};
+use ewe::prelude::*;
+use ewe::report::{Inline, Report};
+
+#[test]
+fn downcast_leaf() {
+    let error = None::<u8>
+        .ok_or_wrap("first")
+        .wrap_err("second")
+        .wrap_err("third")
+        .unwrap_err();
+
+    assert_eq!(error.downcast_ref::<Missing>(), Some(&Missing));
+}
+
+#[test]
+fn downcast_intermediate() {
+    let error = None::<u8>.ok_or_wrap("first").wrap_err("second").unwrap_err();
+
+    let inner = error.downcast_ref::<Wrapped<Missing>>().unwrap();
+    assert_eq!(inner.message(), "first");
+
+    let outer = error.downcast_ref::<Wrapped<Wrapped<Missing>>>().unwrap();
+    assert_eq!(outer.message(), "second");
+}
+
+#[test]
+fn downcast_report() {
+    let error = Err::<(), _>(Report::<Inline<2>>::new(3, "failed"))
+        .wrap_err("outer")
+        .unwrap_err();
+
+    assert_eq!(error.downcast_ref::<Report<Inline<2>>>().unwrap().code(), 3);
+    assert!(error.downcast_ref::<Report<Inline<1>>>().is_none());
+    assert!(error.downcast_ref::<Missing>().is_none());
+}
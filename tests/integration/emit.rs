@@ -0,0 +1,90 @@
+//! Tests for emitting `log` records when errors are wrapped
+
+use core::any::type_name;
+use core::num::ParseIntError;
+use ewe::errors::{Missing, Severity};
+use ewe::prelude::*;
+use ewe::report::{Inline, Report};
+use log::{Level, Log, Metadata, Record};
+use std::sync::{Mutex, Once};
+
+static RECORDS: Mutex<Vec<(Level, String)>> = Mutex::new(Vec::new());
+
+struct Capture;
+
+impl Log for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if record.target() == "ewe" {
+            RECORDS.lock().unwrap().push((record.level(), record.args().to_string()));
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Installs the capturing logger and returns the records containing `needle`
+fn captured(needle: &str) -> Vec<(Level, String)> {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Trace);
+    });
+
+    RECORDS.lock().unwrap().iter().filter(|(_, text)| text.contains(needle)).cloned().collect()
+}
+
+#[test]
+fn emit_on_wrap() {
+    captured("");
+
+    let line = line!() + 1;
+    let _ = "x".parse::<u8>().wrap_err_as(Severity::Warn, "emit_on_wrap");
+
+    let records = captured("emit_on_wrap");
+    let prefix = format!(
+        "wrapped {} as \"emit_on_wrap\" at {}:{line}:",
+        type_name::<ParseIntError>(),
+        file!()
+    );
+
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, Level::Warn);
+    assert!(records[0].1.starts_with(&prefix));
+}
+
+#[test]
+fn emit_on_option() {
+    captured("");
+    let _ = None::<u8>.ok_or_wrap("emit_on_option");
+
+    let records = captured("emit_on_option");
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].0, Level::Error);
+    let prefix = format!("wrapped {} as \"emit_on_option\"", type_name::<Missing>());
+    assert!(records[0].1.starts_with(&prefix));
+}
+
+#[test]
+fn emit_on_context() {
+    captured("");
+    let _ = Report::<Inline<1>>::new(3, "emit_on_context")
+        .with_severity(Severity::Info)
+        .context("frame");
+
+    assert_eq!(captured("emit_on_context"), [(
+        Level::Info,
+        "added context \"frame\" to report \"emit_on_context\" [code 3]".to_owned()
+    )]);
+}
+
+#[test]
+fn emit_nothing_on_ok() {
+    captured("");
+    let _ = Ok::<u8, Report<Inline<1>>>(1).wrap_err("emit_nothing_on_ok");
+
+    assert!(captured("emit_nothing_on_ok").is_empty());
+}
@@ -0,0 +1,133 @@
+//! Tests for emitting `tracing` events when errors are wrapped
+
+use core::any::type_name;
+use core::num::ParseIntError;
+use ewe::errors::Severity;
+use ewe::prelude::*;
+use ewe::report::{Inline, Report};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Metadata, Subscriber};
+
+/// The level and fields of a captured event, with fields in the order they were recorded
+type Captured = (Level, Vec<(&'static str, String)>);
+
+#[derive(Default)]
+struct Capture {
+    events: Arc<Mutex<Vec<Captured>>>
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        if event.metadata().target() == "ewe" {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            self.events.lock().unwrap().push((*event.metadata().level(), fields.0));
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[derive(Default)]
+struct Fields(Vec<(&'static str, String)>);
+
+impl Visit for Fields {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.push((field.name(), value.to_owned()));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0.push((field.name(), format!("{value:?}")));
+    }
+}
+
+/// Runs `f` with a capturing subscriber installed on this thread and returns the captured events
+fn captured(f: impl FnOnce()) -> Vec<Captured> {
+    let capture = Capture::default();
+    let events = Arc::clone(&capture.events);
+    tracing::subscriber::with_default(capture, f);
+
+    let events = events.lock().unwrap();
+    events.clone()
+}
+
+#[test]
+fn events_on_wrap() {
+    let mut location = None;
+    let events = captured(|| {
+        let error = "x".parse::<u8>().wrap_err_as(Severity::Warn, "bad id").unwrap_err();
+        location = Some(error.location().to_string());
+    });
+
+    assert_eq!(events, [(Level::WARN, vec![
+        ("message", "error wrapped".to_owned()),
+        ("error.message", "bad id".to_owned()),
+        ("severity", "warn".to_owned()),
+        ("location", location.unwrap()),
+        ("source_type", type_name::<ParseIntError>().to_owned())
+    ])]);
+}
+
+#[test]
+fn events_on_context() {
+    let mut location = None;
+    let events = captured(|| {
+        let report = Report::<Inline<1>>::new(3, "overheated").with_payload(0.5f64);
+        location = Some(report.location().to_string());
+        let _ = report.with_severity(Severity::Fatal).context("cooling");
+    });
+
+    assert_eq!(events, [(Level::ERROR, vec![
+        ("message", "context added".to_owned()),
+        ("frame", "cooling".to_owned()),
+        ("report.message", "overheated".to_owned()),
+        ("code", "3".to_owned()),
+        ("severity", "fatal".to_owned()),
+        ("location", location.unwrap()),
+        ("payload", "0.5".to_owned())
+    ])]);
+}
+
+#[test]
+fn events_without_payload() {
+    let events = captured(|| {
+        let _ = Report::<Inline<1>>::new(4, "stalled").context("spinning up");
+    });
+
+    assert_eq!(events.len(), 1);
+    assert!(events[0].1.iter().all(|(name, _)| *name != "payload"));
+}
+
+#[test]
+fn events_unique_fields() {
+    let events = captured(|| {
+        let _ = None::<u8>.ok_or_wrap("no id");
+        let _ = Report::<Inline<1>>::new(1, "failed").context("frame");
+    });
+
+    assert_eq!(events.len(), 2);
+    for (_, fields) in events {
+        let mut names: Vec<_> = fields.iter().map(|(name, _)| *name).collect();
+        let count = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), count);
+    }
+}
@@ -0,0 +1,40 @@
+//! Tests for terminating the process with `ExitReport`
+
+use ewe::errors::{Missing, Severe, Severity, Wrapped};
+use ewe::exit::ExitReport;
+use ewe::report::{Inline, Report};
+use std::process::{ExitCode, Termination};
+
+#[test]
+fn exit_report_success() {
+    let report = ExitReport::<Report<Inline<1>>>::from(Ok(()));
+
+    assert_eq!(report.exit_code(), 0);
+    assert_eq!(report.report(), ExitCode::SUCCESS);
+}
+
+#[test]
+fn exit_report_default_code() {
+    let report = ExitReport::new(Err(Report::<Inline<1>>::new(42, "failed")));
+
+    assert_eq!(report.exit_code(), 1);
+    assert_eq!(report.report(), ExitCode::from(1));
+}
+
+#[test]
+fn exit_report_mapped_code() {
+    let error = Wrapped::new("failed", Missing).with_severity(Severity::Fatal);
+    let report = ExitReport::new(Err(error)).with_exit_code(|error| match error.severity() {
+        Severity::Fatal => 70,
+        _ => 1
+    });
+
+    assert_eq!(report.exit_code(), 70);
+    assert_eq!(report.report(), ExitCode::from(70));
+}
+
+#[test]
+fn exit_report_into_result() {
+    let report = ExitReport::new(Err(Report::<Inline<1>>::new(42, "failed")));
+    assert_eq!(report.into_result().unwrap_err().code(), 42);
+}
@@ -0,0 +1,64 @@
+//! Tests for converting errors into `anyhow` and `eyre` reports
+
+use ewe::prelude::*;
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn interop_anyhow_chain() {
+    let wrapped = None::<u8>.ok_or_wrap("no id").wrap_err("no user").unwrap_err();
+    let error = Err::<(), _>(wrapped).into_anyhow().unwrap_err();
+
+    let messages: Vec<_> = error.chain().map(|error| error.to_string()).collect();
+    assert_eq!(messages, [
+        format!("no user\n    at {}", wrapped.location()),
+        format!("no id\n    at {}", wrapped.inner().location()),
+        "value was missing".to_owned()
+    ]);
+
+    let debug = format!("{error:?}");
+    assert!(debug.starts_with("no user\n    at "));
+    assert!(debug.contains("Caused by:"));
+    assert_eq!(debug.matches("value was missing").count(), 1);
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn interop_anyhow_report() {
+    use ewe::report::{Inline, Report};
+
+    let report = Report::<Inline<2>>::new(3, "overheated").context("cooling");
+    let error = Err::<(), _>(report).wrap_err("running").into_anyhow().unwrap_err();
+
+    assert_eq!(error.chain().count(), 2);
+    assert_eq!(error.root_cause().to_string(), format!("{report:#}"));
+}
+
+#[cfg(feature = "anyhow")]
+#[test]
+fn interop_anyhow_question_mark() {
+    fn run() -> anyhow::Result<u8> {
+        Ok("x".parse::<u8>().wrap_err("bad id")?)
+    }
+
+    let error = run().unwrap_err();
+    assert!(error.downcast_ref::<ewe::errors::Wrapped<core::num::ParseIntError>>().is_some());
+}
+
+#[cfg(feature = "eyre")]
+#[test]
+fn interop_eyre_chain() {
+    let wrapped = "x".parse::<u8>().wrap_err("bad id").unwrap_err();
+    let error = Err::<(), _>(wrapped.clone()).into_eyre().unwrap_err();
+
+    let messages: Vec<_> = error.chain().map(|error| error.to_string()).collect();
+    assert_eq!(messages, [
+        format!("bad id\n    at {}", wrapped.location()),
+        "invalid digit found in string".to_owned()
+    ]);
+}
+
+#[cfg(feature = "eyre")]
+#[test]
+fn interop_eyre_ok() {
+    assert_eq!(Ok::<u8, ewe::errors::Missing>(4).into_eyre().unwrap(), 4);
+}
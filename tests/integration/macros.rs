@@ -0,0 +1,76 @@
+//! Tests for the `bail!` and `ensure!` macros
+
+use ewe::errors::{Missing, Wrapped};
+use ewe::report::{Inline, Report};
+use ewe::{bail, ensure};
+
+/// Bails if `fail`, recording the line of the `bail!` in `line`
+fn bail_report(fail: bool, line: &mut u32) -> Result<u8, Report<Inline<1>>> {
+    if fail {
+        *line = line!() + 1;
+        bail!(6, "failed");
+    }
+
+    Ok(1)
+}
+
+/// Ensures `value` is below 10, recording the line of the `ensure!` in `line`
+fn ensure_report(value: u8, line: &mut u32) -> Result<u8, Report<Inline<1>>> {
+    *line = line!() + 1;
+    ensure!(value < 10, 7, "too big");
+    Ok(value)
+}
+
+fn ensure_error(value: Option<u8>) -> Result<u8, Wrapped<Missing>> {
+    ensure!(value.is_some(), Wrapped::new("no value", Missing));
+    Ok(value.unwrap_or_default())
+}
+
+#[test]
+fn bail_creates_report() {
+    let mut line = 0;
+    assert_eq!(bail_report(false, &mut line).unwrap(), 1);
+
+    let report = bail_report(true, &mut line).unwrap_err();
+    assert_eq!(report.code(), 6);
+    assert_eq!(report.message(), "failed");
+    assert_eq!(report.location().file(), file!());
+    assert_eq!(report.location().line(), line);
+}
+
+#[test]
+fn ensure_creates_report() {
+    let mut line = 0;
+    assert_eq!(ensure_report(3, &mut line).unwrap(), 3);
+
+    let report = ensure_report(10, &mut line).unwrap_err();
+    assert_eq!(report.to_string(), "too big [code 7]");
+    assert_eq!(report.location().line(), line);
+}
+
+#[test]
+fn ensure_returns_error() {
+    assert_eq!(ensure_error(Some(2)).unwrap(), 2);
+    assert_eq!(ensure_error(None).unwrap_err().to_string(), "no value: value was missing");
+}
+
+#[test]
+fn bail_converts_error() {
+    #[derive(Debug)]
+    enum AppError {
+        Report(Report<Inline<1>>)
+    }
+
+    impl From<Report<Inline<1>>> for AppError {
+        fn from(report: Report<Inline<1>>) -> Self {
+            Self::Report(report)
+        }
+    }
+
+    fn run() -> Result<(), AppError> {
+        bail!(Report::new(8, "converted"));
+    }
+
+    let AppError::Report(report) = run().unwrap_err();
+    assert_eq!(report.code(), 8);
+}
@@ -0,0 +1,17 @@
+mod wrap;
+mod option;
+mod chain;
+mod report;
+mod macros;
+mod downcast;
+mod severity;
+mod traits;
+mod prelude;
+#[cfg(feature = "std")]
+mod exit;
+#[cfg(feature = "log")]
+mod emit;
+#[cfg(feature = "tracing")]
+mod events;
+#[cfg(any(feature = "anyhow", feature = "eyre"))]
+mod interop;
@@ -0,0 +1,40 @@
+//! Tests for converting absent values into wrapped errors via `OptionExt`
+
+use ewe::errors::Missing;
+use ewe::prelude::*;
+use core::error::Error;
+
+#[test]
+fn ok_or_wrap_some_passes_through() {
+    assert_eq!(Some(5).ok_or_wrap("unused").unwrap(), 5);
+    assert_eq!(Some(5).ok_or_wrap_with(|| unreachable!()).unwrap(), 5);
+}
+
+#[test]
+fn ok_or_wrap_none() {
+    let error = None::<u8>.ok_or_wrap("no value").unwrap_err();
+
+    assert_eq!(error.message(), "no value");
+    assert_eq!(error.inner(), &Missing);
+    assert_eq!(error.source().unwrap().to_string(), "value was missing");
+    assert_eq!(error.to_string(), "no value: value was missing");
+}
+
+#[test]
+fn ok_or_wrap_with_none() {
+    let error = None::<u8>.ok_or_wrap_with(|| "lazy").unwrap_err();
+    assert_eq!(error.to_string(), "lazy: value was missing");
+}
+
+#[test]
+fn ok_or_wrap_records_caller() {
+    let line = line!() + 1;
+    let error = None::<u8>.ok_or_wrap("no value").unwrap_err();
+
+    assert_eq!(error.location().file(), file!());
+    assert_eq!(error.location().line(), line);
+
+    let line = line!() + 1;
+    let error = None::<u8>.ok_or_wrap_with(|| "no value").unwrap_err();
+    assert_eq!(error.location().line(), line);
+}
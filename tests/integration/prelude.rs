@@ -0,0 +1,30 @@
+//! Tests for the minimal prelude
+
+use ewe::prelude::minimal::*;
+use ewe::report::{Inline, Report};
+
+/// A trait whose method would be ambiguous with `ContextExt::context` under the full prelude
+trait Context {
+    fn context(self, label: &str) -> String;
+}
+
+impl<T> Context for Result<T, Report<Inline<1>>> {
+    fn context(self, label: &str) -> String {
+        format!("{label}: {}", self.is_ok())
+    }
+}
+
+#[test]
+fn prelude_minimal_no_context() {
+    let result: Result<(), _> = Err(Report::<Inline<1>>::new(1, "failed"));
+    assert_eq!(result.context("outcome"), "outcome: false");
+}
+
+#[test]
+fn prelude_minimal_wraps() {
+    let error = "x".parse::<u8>().wrap_err("bad id").unwrap_err();
+    assert_eq!(error.message(), "bad id");
+
+    let error = None::<u8>.ok_or_wrap("no id").unwrap_err();
+    assert_eq!(error.message(), "no id");
+}
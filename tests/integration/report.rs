@@ -0,0 +1,175 @@
+//! Tests for `Report` and its storages
+
+use ewe::prelude::*;
+use ewe::report::{Inline, Payload, Report, Storage};
+
+fn messages<S: Storage>(report: &Report<S>) -> Vec<&'static str> {
+    report.frames().iter().map(|frame| frame.message()).collect()
+}
+
+#[test]
+fn report_new() {
+    let report = Report::<Inline<2>>::new(5, "failed");
+
+    assert_eq!(report.code(), 5);
+    assert_eq!(report.message(), "failed");
+    assert_eq!(report.payload(), None);
+    assert!(report.frames().is_empty());
+    assert_eq!(report.elided(), 0);
+    assert_eq!(report.to_string(), "failed [code 5]");
+}
+
+#[test]
+fn report_context() {
+    let report = Report::<Inline<2>>::new(5, "failed").context("inner").context("outer");
+
+    assert_eq!(messages(&report), ["inner", "outer"]);
+    assert_eq!(report.elided(), 0);
+    assert_eq!(report.to_string(), "outer: inner: failed [code 5]");
+}
+
+#[test]
+fn report_context_overflow() {
+    let report = Report::<Inline<2>>::new(5, "failed")
+        .context("first")
+        .context("second")
+        .context("third")
+        .context("fourth");
+
+    assert_eq!(messages(&report), ["first", "second"]);
+    assert_eq!(report.elided(), 2);
+    assert_eq!(report.to_string(), "[2 more]: second: first: failed [code 5]");
+}
+
+#[test]
+fn report_zero_capacity() {
+    let report = Report::<Inline<0>>::new(1, "failed").context("ignored");
+
+    assert!(report.frames().is_empty());
+    assert_eq!(report.elided(), 1);
+    assert_eq!(report.to_string(), "[1 more]: failed [code 1]");
+}
+
+#[test]
+fn report_payload() {
+    let report = Report::<Inline<1>>::new(2, "lossy").with_payload(-3i8);
+    assert_eq!(report.payload(), Some(Payload::Signed(-3)));
+    assert_eq!(report.to_string(), "lossy [code 2, value -3]");
+
+    let report = report.with_payload(u128::MAX);
+    assert_eq!(report.payload(), Some(Payload::Unsigned(u128::MAX)));
+
+    let report = report.with_payload(0.5f32);
+    assert_eq!(report.payload(), Some(Payload::Float32(0.5)));
+
+    let report = report.with_payload(0.5f64);
+    assert_eq!(report.payload(), Some(Payload::Float64(0.5)));
+    assert_eq!(report.to_string(), "lossy [code 2, value 0.5]");
+
+    assert_eq!(Payload::from(usize::MAX), Payload::Unsigned(usize::MAX as u128));
+    assert_eq!(Payload::from(isize::MIN), Payload::Signed(isize::MIN as i128));
+}
+
+#[test]
+fn report_result_context() {
+    let result: Result<(), Report<Inline<3>>> = Err(Report::new(9, "failed"));
+    let report = result.context("inner").context("outer").unwrap_err();
+
+    assert_eq!(report.to_string(), "outer: inner: failed [code 9]");
+    assert_eq!(Ok::<u8, Report<Inline<3>>>(1).context("unused").unwrap(), 1);
+}
+
+#[test]
+fn report_context_locations() {
+    let line = line!() + 1;
+    let report = Report::<Inline<2>>::new(1, "failed").context("inner");
+    let result: Result<(), _> = Err(report);
+    let report = result.context("outer").unwrap_err();
+
+    assert_eq!(report.location().line(), line);
+    assert_eq!(report.frames()[0].location().line(), line);
+    assert_eq!(report.frames()[1].location().line(), line + 2);
+    assert_eq!(report.frames()[1].location().file(), file!());
+}
+
+#[test]
+fn report_alternate() {
+    let report = Report::<Inline<1>>::new(2, "failed")
+        .with_payload(9u8)
+        .context("inner")
+        .context("outer");
+
+    assert_eq!(format!("{report:#}"), format!(
+        "[1 more]\n  caused by: inner\n    at {}\n  caused by: failed [code 2, value 9]\n    at {}",
+        report.frames()[0].location(),
+        report.location()
+    ));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn report_heap_unbounded() {
+    let mut report = Report::<ewe::report::Heap>::new(1, "failed");
+    for _ in 0..20 {
+        report = report.context("frame");
+    }
+
+    assert_eq!(report.frames().len(), 20);
+    assert_eq!(report.elided(), 0);
+}
+
+#[test]
+fn report_default_storage() {
+    let report: Report = Report::new(1, "failed").context("inner").context("outer");
+
+    assert_eq!(messages(&report), ["inner", "outer"]);
+    assert_eq!(report.to_string(), "outer: inner: failed [code 1]");
+}
+
+#[cfg(not(feature = "default-heap"))]
+#[test]
+fn report_default_storage_inline() {
+    let mut report: Report = Report::new(1, "failed");
+    for _ in 0..10 {
+        report = report.context("frame");
+    }
+
+    let copy = report;
+    assert_eq!(copy.frames().len(), 8);
+    assert_eq!(report.elided(), 2);
+}
+
+#[cfg(feature = "default-heap")]
+#[test]
+fn report_default_storage_heap() {
+    let mut report: Report = Report::new(1, "failed");
+    for _ in 0..10 {
+        report = report.context("frame");
+    }
+
+    assert_eq!(report.frames().len(), 10);
+    assert_eq!(report.elided(), 0);
+}
+
+#[test]
+fn report_payload_display() {
+    let display = |payload: Payload| payload.to_string();
+
+    assert_eq!(display(Payload::from(u128::MAX)), "340282366920938463463374607431768211455");
+    assert_eq!(display(Payload::from(i128::MIN)), "-170141183460469231731687303715884105728");
+    assert_eq!(display(Payload::from(1e21f64)), "1000000000000000000000");
+    assert_eq!(display(Payload::from(1.5e-7f64)), "0.00000015");
+    assert_eq!(display(Payload::from(-0.0f64)), "-0");
+    assert_eq!(display(Payload::from(0.5f32)), "0.5");
+    assert_eq!(display(Payload::from(0.1f32)), "0.1");
+    assert_eq!(display(Payload::from(1e-7f32)), "0.0000001");
+    assert_eq!(display(Payload::from(0.1f64)), "0.1");
+    assert_eq!(display(Payload::from(f64::NAN)), "NaN");
+    assert_eq!(display(Payload::from(f64::NEG_INFINITY)), "-inf");
+}
+
+#[test]
+fn report_display_stable() {
+    let report = Report::<Inline<2>>::new(3, "overheated").with_payload(1e16f64).context("cooling");
+    assert_eq!(report.to_string(), "cooling: overheated [code 3, value 10000000000000000]");
+}
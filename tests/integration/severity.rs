@@ -0,0 +1,108 @@
+//! Tests for attaching severities to errors
+
+use ewe::errors::{Missing, Severe, Severity, Wrapped};
+use ewe::prelude::*;
+use ewe::report::{Inline, Report};
+
+#[test]
+fn severity_default() {
+    assert_eq!(Severity::default(), Severity::Error);
+    assert_eq!(Wrapped::new("failed", Missing).severity(), Severity::Error);
+    assert_eq!(Report::<Inline<1>>::new(1, "failed").severity(), Severity::Error);
+}
+
+#[test]
+fn severity_order() {
+    assert!(Severity::Info < Severity::Warn);
+    assert!(Severity::Warn < Severity::Error);
+    assert!(Severity::Error < Severity::Fatal);
+}
+
+#[test]
+fn severity_display() {
+    assert_eq!(Severity::Info.to_string(), "info");
+    assert_eq!(Severity::Warn.to_string(), "warn");
+    assert_eq!(Severity::Error.to_string(), "error");
+    assert_eq!(Severity::Fatal.to_string(), "fatal");
+}
+
+#[test]
+fn severity_per_layer() {
+    let error = None::<u8>
+        .ok_or_wrap("first")
+        .wrap_err_as(Severity::Warn, "second")
+        .wrap_err_as(Severity::Fatal, "third")
+        .unwrap_err();
+
+    assert_eq!(error.severity(), Severity::Fatal);
+    assert_eq!(error.inner().severity(), Severity::Warn);
+    assert_eq!(error.downcast_ref::<Wrapped<Missing>>().unwrap().severity(), Severity::Error);
+    assert_eq!(error.to_string(), "third: second: first: value was missing");
+}
+
+#[test]
+fn severity_inherited() {
+    let error = "x"
+        .parse::<u8>()
+        .wrap_err_as(Severity::Warn, "first")
+        .wrap_err("second")
+        .wrap_err("third")
+        .unwrap_err();
+
+    assert_eq!(error.severity(), Severity::Warn);
+    assert_eq!(error.own_severity(), None);
+    assert_eq!(error.inner().inner().own_severity(), Some(Severity::Warn));
+}
+
+#[test]
+fn severity_inherited_from_report() {
+    let result: Result<(), _> = Err(Report::<Inline<1>>::new(1, "failed")
+        .with_severity(Severity::Info));
+
+    let error = result.wrap_err("loading").wrap_err("starting").unwrap_err();
+    assert_eq!(error.severity(), Severity::Info);
+
+    let error = Wrapped::new("overridden", error).with_severity(Severity::Fatal);
+    assert_eq!(error.severity(), Severity::Fatal);
+    assert_eq!(error.inner().severity(), Severity::Info);
+}
+
+#[test]
+fn severity_report_preserved() {
+    let report = Report::<Inline<2>>::new(1, "failed")
+        .with_severity(Severity::Warn)
+        .context("inner")
+        .context("outer");
+
+    assert_eq!(report.severity(), Severity::Warn);
+
+    let result: Result<(), _> = Err(report);
+    assert_eq!(result.context("more").unwrap_err().severity(), Severity::Warn);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn severity_inherited_from_boxed() {
+    let result: Result<(), Box<dyn std::error::Error>> = Err("disk full".into());
+    let error = result.wrap_err_as(Severity::Warn, "saving").wrap_err("exporting").unwrap_err();
+
+    assert_eq!(error.severity(), Severity::Warn);
+}
+
+#[test]
+fn severity_layer_of_foreign() {
+    /// An error which does not implement `Severe`, as though from another crate
+    #[derive(Debug)]
+    struct Foreign;
+
+    let result: Result<(), _> = Err(Foreign);
+    let error = result.wrap_err_as(Severity::Warn, "saving").unwrap_err();
+    assert_eq!(error.layer_severity(), Severity::Warn);
+
+    let error = Wrapped::new("exporting", error);
+    assert_eq!(error.layer_severity(), Severity::Error);
+    assert_eq!(error.inner().layer_severity(), Severity::Warn);
+
+    let error = error.with_severity(Severity::Fatal);
+    assert_eq!(error.layer_severity(), Severity::Fatal);
+}
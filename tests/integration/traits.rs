@@ -0,0 +1,92 @@
+//! Tests that the error types implement the common derivable traits
+
+use ewe::errors::{Missing, Severity, Wrapped};
+use ewe::prelude::*;
+use ewe::report::{Frame, Inline, Payload, Report};
+use core::fmt::Debug;
+use core::hash::Hash;
+use std::collections::HashSet;
+
+fn assert_all<T: Copy + Clone + Debug + PartialEq + Eq + Hash>() {}
+
+#[test]
+fn traits_implemented() {
+    assert_all::<Missing>();
+    assert_all::<Severity>();
+    assert_all::<Wrapped<Missing>>();
+    assert_all::<Wrapped<Wrapped<Report<Inline<2>>>>>();
+    assert_all::<Report<Inline<0>>>();
+    assert_all::<Report<Inline<4>>>();
+    assert_all::<Frame>();
+    assert_all::<Payload>();
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn traits_implemented_heap() {
+    fn assert_non_copy<T: Clone + Debug + PartialEq + Eq + Hash>() {}
+    assert_non_copy::<Report<ewe::report::Heap>>();
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn traits_implemented_defmt() {
+    fn assert_format<T: defmt::Format>() {}
+    assert_format::<Missing>();
+    assert_format::<Severity>();
+    assert_format::<Wrapped<Wrapped<Missing>>>();
+    assert_format::<Wrapped<Report<Inline<2>>>>();
+    assert_format::<Report<Inline<2>>>();
+    assert_format::<Frame>();
+    assert_format::<Payload>();
+}
+
+#[test]
+fn wrapped_equality() {
+    let make = || None::<u8>.ok_or_wrap("missing").unwrap_err();
+    let (first, second) = (make(), make());
+
+    assert_eq!(first, first);
+    assert_eq!(first, first.clone());
+
+    // Same message and source, but wrapped at a different call site
+    assert_ne!(first, None::<u8>.ok_or_wrap("missing").unwrap_err());
+
+    // Wrapped at the same call site (within the closure) with the same message and source
+    assert_eq!(first, second);
+
+    // Identical except for the severity
+    assert_ne!(first, first.with_severity(Severity::Warn));
+}
+
+#[test]
+fn report_equality() {
+    let make = |value: u8| Report::<Inline<2>>::new(1, "failed").with_payload(value).context("ctx");
+
+    assert_eq!(make(1), make(1));
+    assert_ne!(make(1), make(2));
+    assert_ne!(make(1), make(1).context("more"));
+}
+
+#[test]
+fn payload_equality() {
+    assert_eq!(Payload::from(f64::NAN), Payload::from(f64::NAN));
+    assert_ne!(Payload::from(0.0f64), Payload::from(-0.0f64));
+    assert_ne!(Payload::from(1u8), Payload::from(1i8));
+    assert_ne!(Payload::from(0.5f32), Payload::from(0.5f64));
+    assert_eq!(Payload::from(f32::NAN), Payload::from(f32::NAN));
+    assert_eq!(Payload::from(1u8), Payload::from(1u64));
+}
+
+#[test]
+fn errors_deduplicate() {
+    let make = |message| None::<u8>.ok_or_wrap(message).unwrap_err();
+    let errors: HashSet<_> = ["a", "b", "a", "a"].into_iter().map(make).collect();
+
+    assert_eq!(errors.len(), 2);
+
+    let make = |code| Report::<Inline<1>>::new(code, "failed").with_payload(f64::NAN);
+    let reports: HashSet<_> = [1, 2, 1].into_iter().map(make).collect();
+
+    assert_eq!(reports.len(), 2);
+}
@@ -0,0 +1,170 @@
+//! Tests for wrapping errors via `ResultExt`
+
+use ewe::errors::Wrapped;
+use ewe::prelude::*;
+use ewe::report::{Inline, Report};
+use core::error::Error;
+use core::fmt::{Display, Formatter};
+
+#[derive(Debug, PartialEq)]
+struct Leaf;
+
+impl Display for Leaf {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_str("leaf failed")
+    }
+}
+
+impl Error for Leaf {}
+
+#[test]
+fn wrap_err_ok_passes_through() {
+    assert_eq!(Ok::<u8, Leaf>(7).wrap_err("unused").unwrap(), 7);
+}
+
+#[test]
+fn wrap_err_preserves_source() {
+    let error = Err::<(), _>(Leaf).wrap_err("outer").unwrap_err();
+
+    assert_eq!(error.message(), "outer");
+    assert_eq!(error.inner(), &Leaf);
+    assert_eq!(error.source().unwrap().to_string(), "leaf failed");
+    assert_eq!(error.into_inner(), Leaf);
+}
+
+#[test]
+fn wrap_err_display() {
+    let error = Err::<(), _>(Leaf)
+        .wrap_err("inner")
+        .wrap_err("outer")
+        .unwrap_err();
+
+    assert_eq!(error.to_string(), "outer: inner: leaf failed");
+}
+
+#[test]
+fn wrapped_new() {
+    let error = Wrapped::new("context", Leaf);
+    assert_eq!(error.to_string(), "context: leaf failed");
+}
+
+#[test]
+fn wrap_err_records_caller() {
+    let line = line!() + 1;
+    let error = Err::<(), _>(Leaf).wrap_err("outer").unwrap_err();
+
+    assert_eq!(error.location().file(), file!());
+    assert_eq!(error.location().line(), line);
+}
+
+#[test]
+fn tap_err_runs_on_error_only() {
+    let mut seen = None;
+    let result = Err::<(), _>(Leaf).tap_err(|error| seen = Some(error.to_string()));
+
+    assert_eq!(result, Err(Leaf));
+    assert_eq!(seen.as_deref(), Some("leaf failed"));
+
+    let result = Ok::<u8, Leaf>(1).tap_err(|_| unreachable!());
+    assert_eq!(result, Ok(1));
+}
+
+#[test]
+fn tap_err_sees_wrapped() {
+    let mut message = "";
+    let _ = Err::<(), _>(Leaf)
+        .wrap_err("outer")
+        .tap_err(|error| message = error.message());
+
+    assert_eq!(message, "outer");
+}
+
+#[test]
+fn wrap_err_alternate() {
+    let error = Err::<(), _>(Leaf).wrap_err("inner").wrap_err("outer").unwrap_err();
+
+    assert_eq!(format!("{error:#}"), format!(
+        "outer\n    at {}\n  caused by: inner\n    at {}\n  caused by: leaf failed",
+        error.location(),
+        error.inner().location()
+    ));
+}
+
+#[test]
+fn wrap_err_alternate_foreign_source() {
+    /// An error which has a source but does not render it
+    #[derive(Debug)]
+    struct Config(core::num::ParseIntError);
+
+    impl Display for Config {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("bad config")
+        }
+    }
+
+    impl Error for Config {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let config = Config("x".parse::<u8>().unwrap_err());
+    let error = Err::<(), _>(config).wrap_err("loading").unwrap_err();
+
+    assert_eq!(error.chain().count(), 3);
+    assert_eq!(format!("{error:#}"), format!(
+        "loading\n    at {}\n  caused by: bad config\n  caused by: invalid digit found in string",
+        error.location()
+    ));
+
+}
+
+#[test]
+fn wrap_err_alternate_foreign_between() {
+    /// An error whose source is a wrapped error, but which does not render it
+    #[derive(Debug)]
+    struct Retry(Wrapped<Leaf>);
+
+    impl Display for Retry {
+        fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+            f.write_str("gave up")
+        }
+    }
+
+    impl Error for Retry {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            Some(&self.0)
+        }
+    }
+
+    let inner = Err::<(), _>(Leaf).wrap_err("attempt").unwrap_err();
+    let error = Err::<(), _>(Retry(inner)).wrap_err("outer").unwrap_err();
+
+    assert_eq!(format!("{error:#}"), format!(
+        "outer\n    at {}\n  caused by: gave up\n  caused by: attempt\n    at {}\n  \
+            caused by: leaf failed",
+        error.location(),
+        error.inner().0.location()
+    ));
+}
+
+#[test]
+fn wrap_err_layer_only() {
+    let error = Err::<(), _>(Leaf).wrap_err("inner").wrap_err("outer").unwrap_err();
+
+    assert_eq!(format!("{error:-}"), "outer");
+    assert_eq!(format!("{error:-#}"), format!("outer\n    at {}", error.location()));
+}
+
+#[test]
+fn wrap_err_alternate_report() {
+    let report = Report::<Inline<1>>::new(4, "failed").context("inner");
+    let error = Err::<(), _>(report).wrap_err("outer").unwrap_err();
+
+    assert_eq!(format!("{error:#}"), format!(
+        "outer\n    at {}\n  caused by: inner\n    at {}\n  caused by: failed [code 4]\n    at {}",
+        error.location(),
+        report.frames()[0].location(),
+        report.location()
+    ));
+}